@@ -1,12 +1,19 @@
 //! Holds our [Job](Job) struct and its methods
 
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::sync::mpsc;
 
 /// Contains the `Job` struct, which is used to represent a job in the shell.
 /// - `name` is the name of the job
 /// - `path` is the path to the executable
+/// - `args` are the command line arguments passed to the executable
+/// - `envs` are the environment variables set for the job
+/// - `cwd` is the working directory the job is spawned in
 /// - `status` is the status of the job and is of type [Status](enum.Status.html)
 #[derive(Debug)]
 pub struct Job {
@@ -14,10 +21,134 @@ pub struct Job {
     name: String,
     /// Path to the executable
     path: PathBuf,
+    /// Command line arguments passed to the executable
+    args: Vec<String>,
+    /// Environment variables set for the job
+    envs: Vec<(String, String)>,
+    /// Working directory the job is spawned in, inherits the parent's if `None`
+    cwd: Option<PathBuf>,
+    /// Whether `stdout`/`stderr` should be captured instead of discarded
+    capture: bool,
+    /// Bytes collected from the child's `stdout`, populated by [`wait`](Job::wait) when
+    /// [`capture`](Job::capture_output) is enabled
+    stdout: Vec<u8>,
+    /// Bytes collected from the child's `stderr`, populated by [`wait`](Job::wait) when
+    /// [`capture`](Job::capture_output) is enabled
+    stderr: Vec<u8>,
+    /// Optional retry policy consulted by [`run`](Job::run) when the job ends in
+    /// [`Error`](Status::Error)
+    retry: Option<RetryPolicy>,
+    /// Number of times the command has been spawned by [`run`](Job::run)
+    attempts: u32,
+    /// Windows Job Object the running child is assigned to, used to kill its whole process tree
+    #[cfg(windows)]
+    job_object: Option<WinJobObject>,
     /// Status of the job
     status: Status,
 }
 
+/// Controls how [`run`](Job::run) re-spawns a job that ends in [`Error`](Status::Error).
+///
+/// The delay after failed attempt `n` is `min(initial_backoff * multiplier^(n-1), max_backoff)`,
+/// optionally scaled by a random factor in `[0.5, 1.0)` when [`jitter`](RetryPolicy::jitter) is set
+/// to spread out restarts of many jobs.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of times the command is spawned, including the first attempt
+    pub max_attempts: u32,
+    /// Delay before the second attempt, scaled by `multiplier` on each subsequent failure
+    pub initial_backoff: Duration,
+    /// Upper bound the backoff delay is clamped to
+    pub max_backoff: Duration,
+    /// Growth factor applied to the backoff after every failed attempt
+    pub multiplier: f64,
+    /// Whether to apply a random jitter factor to each delay
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// A policy that makes a single attempt with no retries
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff delay to sleep for before retrying after `attempt` has failed
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        let mut delay = self.initial_backoff.as_secs_f64() * exp;
+        let max = self.max_backoff.as_secs_f64();
+        if delay > max {
+            delay = max;
+        }
+        if self.jitter {
+            delay *= jitter_factor();
+        }
+        Duration::from_secs_f64(delay)
+    }
+}
+
+/// A random factor in `[0.5, 1.0)` derived from the wall clock, used to jitter retry backoff
+/// without pulling in an `rng` dependency.
+fn jitter_factor() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    // `subsec_nanos` is in `0..1_000_000_000`, so dividing by one second in nanoseconds keeps the factor in
+    // `[0.5, 1.0)`.
+    0.5 + (nanos as f64 / 1_000_000_000.0) * 0.5
+}
+
+/// Create a Job Object and assign the process `pid` to it, returning the owning handle.
+///
+/// Returns `None` if any of the Win32 calls fail, in which case [`signal_tree`](Job::signal_tree)
+/// falls back to killing the direct child only. Because the child is already running by the time it
+/// is assigned, a sub-process it spawns in the brief window before assignment can escape the job;
+/// sub-processes spawned afterwards are captured and torn down with it.
+#[cfg(windows)]
+fn assign_to_job_object(pid: u32) -> Option<WinJobObject> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::JobObjects::{AssignProcessToJobObject, CreateJobObjectW};
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
+    };
+
+    // SAFETY: all handles are checked for null and closed on every failure path; the process
+    // handle is closed once the assignment has been made.
+    unsafe {
+        let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+        if job.is_null() {
+            return None;
+        }
+
+        let process = OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid);
+        if process.is_null() {
+            CloseHandle(job);
+            return None;
+        }
+
+        let assigned = AssignProcessToJobObject(job, process);
+        CloseHandle(process);
+
+        if assigned == 0 {
+            CloseHandle(job);
+            return None;
+        }
+
+        Some(WinJobObject(job as isize))
+    }
+}
+
 /// Reports the current status of the job, can be of types:
 /// - `Running`
 /// - `Error(i32)`
@@ -26,27 +157,80 @@ pub struct Job {
 #[derive(Debug)]
 pub enum Status {
     /// The job is currently running
-    Running(Box<tokio::process::Child>),
+    Running {
+        /// Handle to the spawned child process
+        child: Box<tokio::process::Child>,
+        /// Process group the job leads on Unix (equal to the child pid, since the child is spawned
+        /// as a group leader), used to signal the whole process tree on [`stop`](Job::stop);
+        /// `None` on platforms where a group is not tracked
+        pgid: Option<u32>,
+        /// When the child was spawned, used by [`elapsed`](Job::elapsed)
+        started: Instant,
+    },
     /// The job has exited with an error code
-    Error(i32),
+    Error {
+        /// The non-zero / signal exit code
+        code: i32,
+        /// When the job was reaped
+        finished: Instant,
+        /// How long the job ran for, from [`start`](Job::start) to being reaped, used by
+        /// [`elapsed`](Job::elapsed)
+        ran_for: Duration,
+    },
     /// The job has exited normally with the given exit code
-    Exit(i32),
+    Exit {
+        /// The exit code
+        code: i32,
+        /// When the job was reaped
+        finished: Instant,
+        /// How long the job ran for, from [`start`](Job::start) to being reaped, used by
+        /// [`elapsed`](Job::elapsed)
+        ran_for: Duration,
+    },
     /// The job is currently in standby and yet to be [`started`](Job::start) or [`waited`](Job::wait)
     Standby,
 }
 
+/// Owns a Windows Job Object handle, closing it on drop.
+///
+/// A job's child is assigned to this Job Object at [`start`](Job::start) so that
+/// [`signal_tree`](Job::signal_tree) can terminate the whole process tree — the `.bat` wrapper and
+/// every sub-process it spawned — with a single `TerminateJobObject`, rather than leaking orphans.
+#[cfg(windows)]
+#[derive(Debug)]
+struct WinJobObject(isize);
+
+#[cfg(windows)]
+impl Drop for WinJobObject {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` is a handle returned by `CreateJobObjectW` and not closed elsewhere.
+        unsafe {
+            windows_sys::Win32::Foundation::CloseHandle(self.0 as _);
+        }
+    }
+}
+
+/// Which signal [`signal_tree`](Job::signal_tree) should deliver when tearing a job down.
+#[derive(Debug, Copy, Clone)]
+enum Signal {
+    /// Politely ask the process group to terminate (`SIGTERM`)
+    Term,
+    /// Forcibly kill the process group (`SIGKILL`)
+    Kill,
+}
+
 /// Custom Display for Status
 impl Display for Status {
     /// Display the status of the job in a human readable format
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self {
-            Status::Running(_) => {
+            Status::Running { .. } => {
                 write!(f, "Running")
             }
-            Status::Error(code) => {
+            Status::Error { code, .. } => {
                 write!(f, "Error({})", code)
             }
-            Status::Exit(code) => {
+            Status::Exit { code, .. } => {
                 write!(f, "Exit({})", code)
             }
             Status::Standby => {
@@ -72,29 +256,168 @@ impl Job {
         Job {
             name,
             path,
+            args: Vec::new(),
+            envs: Vec::new(),
+            cwd: None,
+            capture: false,
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+            retry: None,
+            attempts: 0,
+            #[cfg(windows)]
+            job_object: None,
             status: Status::Standby,
         }
     }
 
+    /// Add a single argument passed to the executable when it is [`started`](Job::start)
+    ///
+    /// Example:
+    /// ```rust
+    ///  use job_dispatcher::job::Job;
+    ///
+    /// let mut job = Job::new("echo", "/bin/echo").arg("hello");
+    /// ```
+    pub fn arg(mut self, arg: &str) -> Job {
+        self.args.push(arg.to_string());
+        self
+    }
+
+    /// Add multiple arguments passed to the executable when it is [`started`](Job::start)
+    pub fn args<I, S>(mut self, args: I) -> Job
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.args
+            .extend(args.into_iter().map(|a| a.as_ref().to_string()));
+        self
+    }
+
+    /// Set an environment variable for the job, overriding any previous value for the same key
+    pub fn env(mut self, key: &str, val: &str) -> Job {
+        self.envs.push((key.to_string(), val.to_string()));
+        self
+    }
+
+    /// Set the working directory the job is spawned in
+    pub fn current_dir<P: AsRef<Path>>(mut self, cwd: P) -> Job {
+        self.cwd = Some(cwd.as_ref().to_path_buf());
+        self
+    }
+
+    /// Capture the job's `stdout` and `stderr` instead of discarding them
+    ///
+    /// When enabled the child is spawned with piped output which [`wait`](Job::wait) drains
+    /// concurrently; the collected bytes are then available through [`get_stdout`](Job::get_stdout)
+    /// and [`get_stderr`](Job::get_stderr). For tailing a long-running job live use
+    /// [`stream_output`](Job::stream_output) instead.
+    ///
+    /// The pipes are only drained by `wait`, not by [`try_wait`](Job::try_wait) — a job that
+    /// writes more than the OS pipe buffer (commonly ~64KB) will block forever on that write if it
+    /// is only ever polled via `try_wait`. Once `capture_output(true)` is set, `wait` must be
+    /// called to actually finish the job; `try_wait` alone is only safe for status checks in
+    /// between, not as a substitute for it.
+    pub fn capture_output(mut self, capture: bool) -> Job {
+        self.capture = capture;
+        self
+    }
+
+    /// Attach a [`RetryPolicy`] so [`run`](Job::run) re-spawns the job on failure
+    pub fn retry(mut self, policy: RetryPolicy) -> Job {
+        self.retry = Some(policy);
+        self
+    }
+
     /// Start the job, see [wait](Job::wait) for further actions
     pub fn start(&mut self) {
         let mut cmd = tokio::process::Command::new(self.path.clone());
         // kill operation is invoked on a spawned child process when its corresponding Child handle
         // is dropped
-        cmd.kill_on_drop(true)
-        // .creation_flags(0x00000010)
-        // .creation_flags(0x00000008)
-        // .creation_flags(0x08000000)
-        ;
+        cmd.args(&self.args)
+            .envs(self.envs.iter().map(|(k, v)| (k, v)))
+            .kill_on_drop(true);
+
+        // Spawn the child into its own process group / job object so that wrapper shells (a `.bat`
+        // or `.sh` launching sub-processes) can be torn down as a whole tree on `stop`, rather than
+        // leaking orphans.
+        #[cfg(unix)]
+        cmd.process_group(0);
+        #[cfg(windows)]
+        // CREATE_NEW_PROCESS_GROUP
+        cmd.creation_flags(0x00000200);
+
+        if let Some(cwd) = &self.cwd {
+            cmd.current_dir(cwd);
+        }
+
+        let (stdout, stderr) = if self.capture {
+            (Stdio::piped(), Stdio::piped())
+        } else {
+            (Stdio::null(), Stdio::null())
+        };
 
         let child = cmd
-            .stdout(Stdio::null())
+            .stdout(stdout)
             .stdin(Stdio::null())
-            .stderr(Stdio::null())
+            .stderr(stderr)
             .spawn()
             .expect("Failed to spawn process");
 
-        self.status = Status::Running(Box::from(child))
+        // On Unix the child is a group leader, so its pid doubles as the process group id.
+        let pgid = if cfg!(unix) { child.id() } else { None };
+
+        // On Windows, put the child into a fresh Job Object so `stop` can tear down the whole tree.
+        #[cfg(windows)]
+        {
+            self.job_object = child.id().and_then(assign_to_job_object);
+        }
+
+        self.status = Status::Running {
+            child: Box::from(child),
+            pgid,
+            started: Instant::now(),
+        }
+    }
+
+    /// Hand back line-delimited `tokio::sync::mpsc` receivers for the running job's `stdout` and
+    /// `stderr` so callers can tail its logs live
+    ///
+    /// Requires the job to have been started with [`capture_output(true)`](Job::capture_output).
+    /// This takes ownership of the child's pipes, so a later [`wait`](Job::wait) will not also
+    /// populate [`get_stdout`](Job::get_stdout)/[`get_stderr`](Job::get_stderr). Returns `None` if
+    /// the job is not [`Running`](Status::Running).
+    pub fn stream_output(&mut self) -> Option<(mpsc::Receiver<String>, mpsc::Receiver<String>)> {
+        match &mut self.status {
+            Status::Running { child, .. } => {
+                let (out_tx, out_rx) = mpsc::channel(64);
+                let (err_tx, err_rx) = mpsc::channel(64);
+
+                if let Some(stdout) = child.stdout.take() {
+                    tokio::spawn(async move {
+                        let mut lines = BufReader::new(stdout).lines();
+                        while let Ok(Some(line)) = lines.next_line().await {
+                            if out_tx.send(line).await.is_err() {
+                                break;
+                            }
+                        }
+                    });
+                }
+                if let Some(stderr) = child.stderr.take() {
+                    tokio::spawn(async move {
+                        let mut lines = BufReader::new(stderr).lines();
+                        while let Ok(Some(line)) = lines.next_line().await {
+                            if err_tx.send(line).await.is_err() {
+                                break;
+                            }
+                        }
+                    });
+                }
+
+                Some((out_rx, err_rx))
+            }
+            _ => None,
+        }
     }
 
     /// Call [`start`](Job::start) before this method
@@ -107,17 +430,58 @@ impl Job {
     /// was not [`Running`](Status::Running)
     pub async fn wait(&mut self) -> Result<(), i32> {
         match &mut self.status {
-            Status::Running(child) => {
+            Status::Running { child, started, .. } => {
+                let started = *started;
+                // Drain both pipes concurrently while the process runs so a full pipe buffer can
+                // never deadlock the child; the handles are `None` unless `capture_output` was set.
+                let out_task = child.stdout.take().map(|mut out| {
+                    tokio::spawn(async move {
+                        let mut buf = Vec::new();
+                        let _ = out.read_to_end(&mut buf).await;
+                        buf
+                    })
+                });
+                let err_task = child.stderr.take().map(|mut err| {
+                    tokio::spawn(async move {
+                        let mut buf = Vec::new();
+                        let _ = err.read_to_end(&mut buf).await;
+                        buf
+                    })
+                });
+
                 let status = child.wait().await.expect("Failed to wait on child");
+
+                if let Some(task) = out_task {
+                    self.stdout = task.await.unwrap_or_default();
+                }
+                if let Some(task) = err_task {
+                    self.stderr = task.await.unwrap_or_default();
+                }
+
+                let finished = Instant::now();
+                let ran_for = finished.duration_since(started);
                 match status.code() {
                     Some(code) => {
-                        self.status = Status::Exit(code);
-                        if code != 0 {
-                            self.status = Status::Error(code);
-                        }
+                        self.status = if code != 0 {
+                            Status::Error {
+                                code,
+                                finished,
+                                ran_for,
+                            }
+                        } else {
+                            Status::Exit {
+                                code,
+                                finished,
+                                ran_for,
+                            }
+                        };
                     }
                     None => {
-                        self.status = Status::Error(-1);
+                        self.status = Status::Error {
+                            code: -1,
+                            finished,
+                            ran_for,
+                        };
                     }
                 }
                 Ok(())
@@ -126,6 +490,217 @@ impl Job {
         }
     }
 
+    /// Check whether the job has finished without blocking
+    ///
+    /// Polls the child via tokio's [`Child::try_wait`](tokio::process::Child::try_wait),
+    /// transitioning [`Running`](Status::Running) to [`Exit`](Status::Exit)/[`Error`](Status::Error)
+    /// when it has ended. Returns `Ok(Some(code))` once the job has exited, `Ok(None)` while it is
+    /// still running, and `Err(-1)` if the job was not [`Running`](Status::Running).
+    ///
+    /// Unlike [`wait`](Job::wait), this never reads the job's piped `stdout`/`stderr` — if
+    /// [`capture_output(true)`](Job::capture_output) is set, polling exclusively through this
+    /// method lets the child's output accumulate unread in the OS pipe buffer, and the child will
+    /// block forever once it fills (commonly ~64KB) and never reach the `Exit`/`Error` this method
+    /// waits for. Call `wait` to actually finish a job started with output capture; use `try_wait`
+    /// only for status checks in between.
+    pub fn try_wait(&mut self) -> Result<Option<i32>, i32> {
+        match &mut self.status {
+            Status::Running { child, started, .. } => match child.try_wait() {
+                Ok(Some(status)) => {
+                    let finished = Instant::now();
+                    let ran_for = finished.duration_since(*started);
+                    let code = status.code().unwrap_or(-1);
+                    self.status = if code != 0 {
+                        Status::Error {
+                            code,
+                            finished,
+                            ran_for,
+                        }
+                    } else {
+                        Status::Exit {
+                            code,
+                            finished,
+                            ran_for,
+                        }
+                    };
+                    Ok(Some(code))
+                }
+                Ok(None) => Ok(None),
+                Err(_) => Err(-1),
+            },
+            _ => Err(-1),
+        }
+    }
+
+    /// Returns the OS process id of the job while it is [`Running`](Status::Running)
+    ///
+    /// Returns `None` once the child has been reaped, so a stale pid can never be addressed.
+    pub fn pid(&self) -> Option<u32> {
+        match &self.status {
+            Status::Running { child, .. } => child.id(),
+            _ => None,
+        }
+    }
+
+    /// Returns how long the job ran for
+    ///
+    /// While [`Running`](Status::Running) this is the time elapsed since [`start`](Job::start); once
+    /// reaped it is the fixed duration from `start` to exit, so it keeps returning a value after
+    /// [`wait`](Job::wait)/[`try_wait`](Job::try_wait) complete. Returns `None` only in
+    /// [`Standby`](Status::Standby), before the job has ever been started.
+    pub fn elapsed(&self) -> Option<Duration> {
+        match &self.status {
+            Status::Running { started, .. } => Some(started.elapsed()),
+            Status::Error { ran_for, .. } | Status::Exit { ran_for, .. } => Some(*ran_for),
+            Status::Standby => None,
+        }
+    }
+
+    /// Spawn the job and wait for it, honouring its [`RetryPolicy`](Job::retry)
+    ///
+    /// Repeatedly [`start`](Job::start)s and [`wait`](Job::wait)s the command; whenever it ends in
+    /// [`Error`](Status::Error) and attempts remain, it sleeps for the policy's
+    /// [`backoff`](RetryPolicy::backoff) and re-spawns. Returns `Ok(())` once the job either
+    /// succeeds or exhausts its attempts — inspect [`get_status`](Job::get_status) and
+    /// [`attempts`](Job::attempts) to tell "succeeded on attempt 3" from "failed permanently".
+    pub async fn run(&mut self) -> Result<(), i32> {
+        let policy = self.retry.clone().unwrap_or_default();
+        // Reset so `run` can be called more than once on the same job without the stale count
+        // immediately exhausting the retry budget.
+        self.attempts = 0;
+        loop {
+            self.attempts += 1;
+            self.start();
+            self.wait().await?;
+
+            match self.status {
+                Status::Error { .. } if self.attempts < policy.max_attempts => {
+                    tokio::time::sleep(policy.backoff(self.attempts)).await;
+                }
+                // Succeeded, or out of attempts: surface the terminal status.
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Number of times the command has been spawned by [`run`](Job::run)
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Gracefully stop a [`Running`](Status::Running) job, escalating if it refuses to exit
+    ///
+    /// Sends `SIGTERM` to the job's process group (so shell wrappers and the sub-processes they
+    /// launched are all asked to quit), waits up to `grace` for it to exit, and escalates to
+    /// `SIGKILL` if it is still alive. On Windows the child's Job Object is terminated, taking the
+    /// whole process tree (the wrapper and its sub-processes) with it. After signalling, the child
+    /// is reaped and the status transitions to [`Exit`](Status::Exit)/[`Error`](Status::Error);
+    /// returns `Err(-1)` if the job was not running.
+    pub async fn stop(&mut self, grace: Duration) -> Result<(), i32> {
+        // Copy the Job Object handle out before borrowing `status` mutably (no-op on Unix).
+        #[cfg(windows)]
+        let job = self.job_object.as_ref().map(|j| j.0);
+        #[cfg(not(windows))]
+        let job: Option<isize> = None;
+
+        {
+            let (child, pgid) = match &mut self.status {
+                Status::Running { child, pgid, .. } => (child, *pgid),
+                _ => return Err(-1),
+            };
+
+            Self::signal_tree(child, pgid, job, Signal::Term);
+
+            let start = std::time::Instant::now();
+            loop {
+                match child.try_wait() {
+                    Ok(Some(_)) => break,
+                    Ok(None) => {
+                        if start.elapsed() >= grace {
+                            Self::signal_tree(child, pgid, job, Signal::Kill);
+                            break;
+                        }
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+
+        self.wait().await
+    }
+
+    /// Immediately `SIGKILL` the job's process group without waiting for a graceful exit
+    ///
+    /// A fast-path counterpart to [`stop`](Job::stop); returns `Err(-1)` if the job was not
+    /// [`Running`](Status::Running).
+    pub async fn kill_now(&mut self) -> Result<(), i32> {
+        #[cfg(windows)]
+        let job = self.job_object.as_ref().map(|j| j.0);
+        #[cfg(not(windows))]
+        let job: Option<isize> = None;
+
+        {
+            let (child, pgid) = match &mut self.status {
+                Status::Running { child, pgid, .. } => (child, *pgid),
+                _ => return Err(-1),
+            };
+            Self::signal_tree(child, pgid, job, Signal::Kill);
+        }
+
+        self.wait().await
+    }
+
+    /// Signal the whole process tree of a running child.
+    ///
+    /// `job` carries the Windows Job Object handle and is unused on Unix, where the process group
+    /// (`pgid`) is signalled instead.
+    #[cfg(unix)]
+    fn signal_tree(
+        child: &mut tokio::process::Child,
+        pgid: Option<u32>,
+        _job: Option<isize>,
+        signal: Signal,
+    ) {
+        let sig = match signal {
+            Signal::Term => libc::SIGTERM,
+            Signal::Kill => libc::SIGKILL,
+        };
+        // Negating the pid targets the entire process group; fall back to the bare pid if the
+        // group is unknown.
+        if let Some(pgid) = pgid {
+            unsafe {
+                libc::kill(-(pgid as i32), sig);
+            }
+        } else if let Some(id) = child.id() {
+            unsafe {
+                libc::kill(id as i32, sig);
+            }
+        }
+    }
+
+    /// Signal the whole process tree of a running child.
+    ///
+    /// Terminating the Job Object kills the child and every sub-process it spawned; if the child
+    /// could not be assigned to one, fall back to killing the direct child. Windows has no
+    /// graceful-vs-forceful distinction here, so both signals terminate.
+    #[cfg(windows)]
+    fn signal_tree(
+        child: &mut tokio::process::Child,
+        _pgid: Option<u32>,
+        job: Option<isize>,
+        _signal: Signal,
+    ) {
+        match job {
+            Some(handle) => unsafe {
+                windows_sys::Win32::System::JobObjects::TerminateJobObject(handle as _, 1);
+            },
+            None => {
+                let _ = child.start_kill();
+            }
+        }
+    }
+
     /// Returns the name of the job
     pub fn get_name(&self) -> &String {
         &self.name
@@ -140,4 +715,20 @@ impl Job {
     pub fn get_status(&self) -> &Status {
         &self.status
     }
+
+    /// Returns the bytes captured from the job's `stdout`
+    ///
+    /// Only populated after [`wait`](Job::wait) completes and when the job was started with
+    /// [`capture_output(true)`](Job::capture_output); empty otherwise.
+    pub fn get_stdout(&self) -> &[u8] {
+        &self.stdout
+    }
+
+    /// Returns the bytes captured from the job's `stderr`
+    ///
+    /// Only populated after [`wait`](Job::wait) completes and when the job was started with
+    /// [`capture_output(true)`](Job::capture_output); empty otherwise.
+    pub fn get_stderr(&self) -> &[u8] {
+        &self.stderr
+    }
 }
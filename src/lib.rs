@@ -2,25 +2,24 @@
 //!
 //! Example:
 //!
-//! ```rust
+//! ```
 //! use job_dispatcher::job::Job;
 //!
 //! #[tokio::main]
 //! async fn main() {
-//!     let path = "C:\\Users\\sn99\\Downloads\\privacy-script.bat";
-//!
-//! let mut job = Job::new("trash", path);
+//!     let mut job = Job::new("check", "/bin/true");
 //!
-//! // start a job
+//!     // start the job
 //!     job.start();
 //!
-//! // check is the job is done (does not block)
-//!     println!("Job done?: {:?}", job.try_wait());
-//!
-//! // wait for it to finish (will block), will error out if previous statement returns `Ok`, use `match` to handle them
-//!     job.wait().await.expect("Job failed");
+//!     // check if the job is done (does not block); `try_wait` can itself observe the child
+//!     // exit, so only fall back to `wait` when it reports the job is still running
+//!     if let Ok(None) = job.try_wait() {
+//!         job.wait().await.expect("job failed");
+//!     }
 //!
-//! println!("Job exited with code: {:?}", job.get_status());
+//!     println!("Job exited with code: {:?}", job.get_status());
 //! }
 //! ```
 pub mod job;
+pub mod manager;
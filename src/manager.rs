@@ -0,0 +1,174 @@
+//! Holds our [JobManager](JobManager), a concurrent supervisor for many [`Job`](crate::job::Job)s
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{Notify, Semaphore};
+use tokio::task::JoinHandle;
+
+use crate::job::{Job, Status};
+
+/// Grace period a cancelled job is given to exit before it is force-killed
+const CANCEL_GRACE: Duration = Duration::from_secs(5);
+
+/// A clone-able snapshot of where a managed job is in its lifecycle
+///
+/// Mirrors [`Status`](crate::job::Status) but drops the live [`Child`](tokio::process::Child)
+/// handle so that [`status`](JobManager::status) can hand out a copy without blocking the
+/// supervising task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobState {
+    /// Submitted but still waiting for a concurrency permit
+    Queued,
+    /// Holding a permit and executing
+    Running,
+    /// Exited normally with the given code
+    Exit(i32),
+    /// Exited with a non-zero / signal code
+    Error(i32),
+    /// Torn down by [`cancel`](JobManager::cancel)
+    Cancelled,
+}
+
+/// Per-job bookkeeping shared between the manager and the supervising task
+struct Slot {
+    /// Latest observed state of the job
+    state: JobState,
+    /// Pinged by [`cancel`](JobManager::cancel) to ask the supervisor to stop the job
+    cancel: Arc<Notify>,
+}
+
+/// Schedules and supervises a collection of [`Job`](crate::job::Job)s keyed by name.
+///
+/// Each submitted job is driven by its own background task that first waits for a permit from a
+/// shared [`Semaphore`] (bounding how many run at once), then spawns the child and reaps it,
+/// publishing the outcome into shared state so [`status`](JobManager::status) never blocks.
+pub struct JobManager {
+    /// Bounds the number of jobs executing concurrently
+    semaphore: Arc<Semaphore>,
+    /// Shared state for every submitted job, keyed by job name
+    slots: Arc<Mutex<HashMap<String, Slot>>>,
+    /// Supervising task per job name, awaited by [`join_all`](JobManager::join_all)
+    tasks: HashMap<String, JoinHandle<()>>,
+}
+
+impl JobManager {
+    /// Creates a manager that runs at most `max_concurrency` jobs at the same time
+    ///
+    /// Example:
+    /// ```rust
+    ///  use job_dispatcher::manager::JobManager;
+    ///
+    /// let manager = JobManager::new(4);
+    /// ```
+    pub fn new(max_concurrency: usize) -> JobManager {
+        JobManager {
+            semaphore: Arc::new(Semaphore::new(max_concurrency)),
+            slots: Arc::new(Mutex::new(HashMap::new())),
+            tasks: HashMap::new(),
+        }
+    }
+
+    /// Submit a job to be scheduled, returning immediately
+    ///
+    /// The job starts [`Queued`](JobState::Queued) and only [`start`](crate::job::Job::start)s once
+    /// a concurrency permit is free. Submitting a name that is already present aborts the previous
+    /// supervising task before replacing it, so a stale supervisor cannot keep holding a permit or
+    /// racing to update the shared slot.
+    pub fn submit(&mut self, mut job: Job) {
+        let name = job.get_name().clone();
+        let cancel = Arc::new(Notify::new());
+
+        self.slots.lock().unwrap().insert(
+            name.clone(),
+            Slot {
+                state: JobState::Queued,
+                cancel: cancel.clone(),
+            },
+        );
+
+        let semaphore = self.semaphore.clone();
+        let slots = self.slots.clone();
+        let task_name = name.clone();
+
+        let handle = tokio::spawn(async move {
+            // Queue until a permit frees up; the permit is released when `_permit` drops.
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+
+            set_state(&slots, &task_name, JobState::Running);
+
+            let outcome = tokio::select! {
+                // `run` spawns the job and applies its retry policy before settling.
+                res = job.run() => match res {
+                    Ok(()) => terminal_state(job.get_status()),
+                    Err(_) => JobState::Error(-1),
+                },
+                _ = cancel.notified() => {
+                    let _ = job.stop(CANCEL_GRACE).await;
+                    JobState::Cancelled
+                }
+            };
+
+            set_state(&slots, &task_name, outcome);
+        });
+
+        // Dropping a `JoinHandle` detaches the task rather than stopping it, so an already-running
+        // supervisor for this name must be aborted explicitly before we forget its handle.
+        if let Some(previous) = self.tasks.insert(name, handle) {
+            previous.abort();
+        }
+    }
+
+    /// Returns a snapshot of the named job's state, or `None` if it was never submitted
+    pub fn status(&self, name: &str) -> Option<JobState> {
+        self.slots
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|slot| slot.state.clone())
+    }
+
+    /// Ask the named job to stop, escalating to a kill after a short grace period
+    ///
+    /// Does nothing if the name is unknown or the job has already finished.
+    pub fn cancel(&self, name: &str) {
+        if let Some(slot) = self.slots.lock().unwrap().get(name) {
+            slot.cancel.notify_one();
+        }
+    }
+
+    /// Wait for every submitted job to reach a terminal state and return the final map
+    ///
+    /// Consumes the supervising tasks, so the manager holds no outstanding work afterwards.
+    pub async fn join_all(&mut self) -> HashMap<String, JobState> {
+        let tasks = std::mem::take(&mut self.tasks);
+        for (_, handle) in tasks {
+            let _ = handle.await;
+        }
+
+        self.slots
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, slot)| (name.clone(), slot.state.clone()))
+            .collect()
+    }
+}
+
+/// Map a finished job's [`Status`] onto the clone-able [`JobState`].
+fn terminal_state(status: &Status) -> JobState {
+    match status {
+        Status::Exit { code, .. } => JobState::Exit(*code),
+        Status::Error { code, .. } => JobState::Error(*code),
+        // A job that reports anything else after `wait` returned `Ok` is treated as a failure.
+        _ => JobState::Error(-1),
+    }
+}
+
+/// Publish `state` for `name` into the shared slot map.
+fn set_state(slots: &Arc<Mutex<HashMap<String, Slot>>>, name: &str, state: JobState) {
+    if let Some(slot) = slots.lock().unwrap().get_mut(name) {
+        slot.state = state;
+    }
+}
@@ -0,0 +1,83 @@
+//! Integration tests for [`Job`](job_dispatcher::job::Job)'s builder API, output capture, and
+//! graceful/forceful termination.
+
+use std::time::Duration;
+
+use job_dispatcher::job::{Job, Status};
+
+/// Captured `stdout` holds the real bytes written by the child.
+#[tokio::test]
+async fn captures_stdout_from_the_child() {
+    let mut job = Job::new("echo", "/bin/echo")
+        .arg("hello")
+        .capture_output(true);
+
+    job.start();
+    job.wait().await.unwrap();
+
+    assert_eq!(job.get_stdout(), b"hello\n");
+}
+
+/// A variable set with `.env()` is visible to the spawned shell.
+#[tokio::test]
+async fn env_var_is_visible_to_the_child() {
+    let mut job = Job::new("print-env", "/bin/sh")
+        .arg("-c")
+        .arg("echo $GREETING")
+        .env("GREETING", "hi")
+        .capture_output(true);
+
+    job.start();
+    job.wait().await.unwrap();
+
+    assert_eq!(job.get_stdout(), b"hi\n");
+}
+
+/// The job is spawned in the directory given to `.current_dir()`.
+#[tokio::test]
+async fn runs_in_the_given_working_directory() {
+    let dir = std::env::temp_dir();
+    let mut job = Job::new("pwd", "/bin/pwd")
+        .current_dir(&dir)
+        .capture_output(true);
+
+    job.start();
+    job.wait().await.unwrap();
+
+    let stdout = String::from_utf8(job.get_stdout().to_vec()).unwrap();
+    assert_eq!(stdout.trim(), dir.canonicalize().unwrap().to_str().unwrap());
+}
+
+/// `stop()` reaps a running job and leaves no orphan behind.
+#[tokio::test]
+async fn stop_reaps_a_running_job() {
+    let mut job = Job::new("long", "/bin/sleep").arg("30");
+    job.start();
+
+    let pid = job.pid().expect("job should be running");
+    job.stop(Duration::from_millis(200)).await.unwrap();
+
+    assert!(matches!(
+        job.get_status(),
+        Status::Error { .. } | Status::Exit { .. }
+    ));
+    assert!(job.pid().is_none());
+    // The process is gone: signalling it again fails with ESRCH.
+    assert!(unsafe { libc::kill(pid as i32, 0) } == -1);
+}
+
+/// `kill_now()` forcibly reaps a running job without waiting for a graceful exit.
+#[tokio::test]
+async fn kill_now_reaps_a_running_job() {
+    let mut job = Job::new("long", "/bin/sleep").arg("30");
+    job.start();
+
+    let pid = job.pid().expect("job should be running");
+    job.kill_now().await.unwrap();
+
+    assert!(matches!(
+        job.get_status(),
+        Status::Error { .. } | Status::Exit { .. }
+    ));
+    assert!(unsafe { libc::kill(pid as i32, 0) } == -1);
+}
@@ -0,0 +1,54 @@
+//! Integration tests for [`JobManager`](job_dispatcher::manager::JobManager) scheduling.
+
+use std::time::Duration;
+
+use job_dispatcher::job::Job;
+use job_dispatcher::manager::{JobManager, JobState};
+
+/// Every submitted job is driven to a terminal state and reported by `join_all`.
+#[tokio::test]
+async fn runs_every_submitted_job() {
+    let mut manager = JobManager::new(4);
+    for i in 0..4 {
+        manager.submit(Job::new(&format!("ok-{i}"), "/bin/true"));
+    }
+
+    let outcomes = manager.join_all().await;
+
+    assert_eq!(outcomes.len(), 4);
+    for i in 0..4 {
+        assert_eq!(outcomes.get(&format!("ok-{i}")), Some(&JobState::Exit(0)));
+    }
+}
+
+/// No more than `max_concurrency` jobs are ever `Running` at the same time.
+#[tokio::test]
+async fn bounds_concurrency() {
+    let mut manager = JobManager::new(2);
+    for i in 0..4 {
+        manager.submit(Job::new(&format!("sleep-{i}"), "/bin/sleep").arg("0.5"));
+    }
+
+    // Give the scheduler a moment to hand out the two permits, then take a snapshot.
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let running = (0..4)
+        .filter(|i| manager.status(&format!("sleep-{i}")) == Some(JobState::Running))
+        .count();
+    assert!(running <= 2, "expected at most 2 running, saw {running}");
+
+    let outcomes = manager.join_all().await;
+    assert!(outcomes.values().all(|s| *s == JobState::Exit(0)));
+}
+
+/// Cancelling a running job tears it down and reports it as `Cancelled`.
+#[tokio::test]
+async fn cancel_stops_a_running_job() {
+    let mut manager = JobManager::new(1);
+    manager.submit(Job::new("long", "/bin/sleep").arg("30"));
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    manager.cancel("long");
+
+    let outcomes = manager.join_all().await;
+    assert_eq!(outcomes.get("long"), Some(&JobState::Cancelled));
+}
@@ -0,0 +1,73 @@
+//! Integration tests for [`Job::run`](job_dispatcher::job::Job::run)'s retry policy.
+
+use std::time::Duration;
+
+use job_dispatcher::job::{Job, RetryPolicy, Status};
+
+/// A policy with short, deterministic backoff for tests.
+fn fast_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(50),
+        multiplier: 2.0,
+        jitter: false,
+    }
+}
+
+/// A flaky command that fails until its third invocation eventually succeeds.
+#[tokio::test]
+async fn retries_until_a_flaky_command_succeeds() {
+    let counter = std::env::temp_dir().join("job_dispatcher_flaky.count");
+    let _ = std::fs::remove_file(&counter);
+    // Increment a counter file each run and only exit 0 on the third attempt.
+    let script = format!(
+        "n=$(cat {p} 2>/dev/null || echo 0); n=$((n+1)); echo $n > {p}; [ \"$n\" -ge 3 ]",
+        p = counter.display()
+    );
+
+    let mut job = Job::new("flaky", "/bin/sh")
+        .arg("-c")
+        .arg(&script)
+        .retry(fast_policy(5));
+
+    job.run().await.unwrap();
+
+    assert_eq!(job.attempts(), 3);
+    assert!(matches!(job.get_status(), Status::Exit { code: 0, .. }));
+
+    let _ = std::fs::remove_file(&counter);
+}
+
+/// A command that always fails surfaces a terminal error once attempts are exhausted.
+#[tokio::test]
+async fn gives_up_after_max_attempts() {
+    let mut job = Job::new("always-fail", "/bin/false").retry(fast_policy(3));
+
+    job.run().await.unwrap();
+
+    assert_eq!(job.attempts(), 3);
+    assert!(matches!(job.get_status(), Status::Error { .. }));
+}
+
+/// Without a policy a job is attempted exactly once.
+#[tokio::test]
+async fn no_policy_runs_once() {
+    let mut job = Job::new("once", "/bin/false");
+
+    job.run().await.unwrap();
+
+    assert_eq!(job.attempts(), 1);
+    assert!(matches!(job.get_status(), Status::Error { .. }));
+}
+
+/// A job that succeeds first time does not retry.
+#[tokio::test]
+async fn success_does_not_retry() {
+    let mut job = Job::new("ok", "/bin/true").retry(fast_policy(3));
+
+    job.run().await.unwrap();
+
+    assert_eq!(job.attempts(), 1);
+    assert!(matches!(job.get_status(), Status::Exit { code: 0, .. }));
+}